@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use crate::{Span, TSXToken};
+
+/// A textual edit that can be applied at a [`Span`] to fix up a [`ParseError`], e.g. "replace
+/// `const` with `let`". Downstream tools (editors, `--fix`-style CLIs) can render these directly.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Suggestion {
+	pub span: Span,
+	pub replacement: String,
+	pub message: Cow<'static, str>,
+}
+
+impl Suggestion {
+	pub fn new(
+		span: Span,
+		replacement: impl Into<String>,
+		message: impl Into<Cow<'static, str>>,
+	) -> Self {
+		Self { span, replacement: replacement.into(), message: message.into() }
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseErrors<'a> {
+	UnexpectedToken { expected: &'a [TSXToken], found: TSXToken },
+	/// The reader ran out of tokens while a grammar production still expected more, e.g. `const x =`
+	/// with nothing after the `=`. Distinct from `UnexpectedToken` so that a caller feeding input
+	/// incrementally (a REPL) can tell "just needs more input" apart from a real syntax error.
+	/// `expecting` lists the token(s) that would have continued the statement, so a REPL can tell
+	/// the user (or pre-fill) what comes next.
+	UnexpectedEndOfInput { expecting: &'a [TSXToken] },
+	/// The same name is bound twice by `let`/`const` in one block. `original` is where it was first
+	/// declared; the error's own position is the clashing redeclaration.
+	VariableRedeclaration { name: String, original: Span },
+	/// `using`/`await using` only allow a plain binding identifier, e.g. `using { a } = r` is
+	/// rejected even though the equivalent `const` destructuring is valid.
+	InvalidUsingBindingPattern,
+	/// A declaration that requires an initializer (e.g. `const`) had none at all, as in `const x;`.
+	/// Distinct from a malformed expression *after* a present `=` (that's just `UnexpectedToken`/
+	/// a propagated expression error) so that only this exact case gets the "insert a value" fix-it.
+	ExpectedInitializer,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+	pub reason: ParseErrors<'static>,
+	pub position: Span,
+	pub suggestions: Vec<Suggestion>,
+}
+
+impl ParseError {
+	pub fn new(reason: ParseErrors<'static>, position: Span) -> Self {
+		Self { reason, position, suggestions: Vec::new() }
+	}
+
+	/// Set this error's fix-it [`Suggestion`]s, replacing any already attached, e.g. an edit that
+	/// would turn an invalid `const x;` into valid `let x;`.
+	pub fn with_suggestions(mut self, suggestions: Vec<Suggestion>) -> Self {
+		self.suggestions = suggestions;
+		self
+	}
+
+	pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+		self.suggestions.push(suggestion);
+		self
+	}
+
+	/// Whether this error is just the reader running out of tokens mid-production, rather than a
+	/// real grammar violation. A REPL can use this to keep buffering lines and re-parse instead of
+	/// surfacing a hard error to the user.
+	pub fn is_incomplete(&self) -> bool {
+		matches!(self.reason, ParseErrors::UnexpectedEndOfInput { .. })
+	}
+}
+
+pub(crate) fn parse_lexing_error() -> ParseError {
+	ParseError::new(ParseErrors::UnexpectedToken { expected: &[], found: TSXToken::EOS }, Span::default())
+}
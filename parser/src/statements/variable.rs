@@ -1,14 +1,23 @@
 use std::borrow::Cow;
 
 use iterator_endiate::EndiateIteratorExt;
+use smallvec::SmallVec;
 
 use super::{ASTNode, ParseError, Span, Token, TokenReader};
 use crate::{
-	errors::parse_lexing_error, tsx_keywords, Expression, Keyword, ParseResult, ParseSettings,
-	TSXKeyword, TSXToken, TypeReference, VariableField, VariableFieldInSourceCode, WithComment,
+	errors::{parse_lexing_error, Suggestion},
+	tsx_keywords, ArrayDestructuringField, Expression, Keyword, ObjectDestructuringField,
+	ParseResult, ParseSettings, TSXKeyword, TSXToken, TypeReference, VariableField,
+	VariableFieldInSourceCode, WithComment,
 };
 use visitable_derive::Visitable;
 
+/// A representative continuation token for a position expecting an identifier, expression, or type
+/// reference -- all of which, in this grammar, are introduced by a name. Used to populate
+/// [`crate::ParseErrors::UnexpectedEndOfInput`]'s `expecting` so a REPL can report what would
+/// complete the statement.
+const EXPECTING_NAME: &[TSXToken] = &[TSXToken::IdentifierLiteral(String::new())];
+
 /// This is for `const` declarations vs `let` and `var` declarations
 pub trait DeclarationExpression:
 	PartialEq + Clone + std::fmt::Debug + Send + std::marker::Sync + crate::Visitable
@@ -38,7 +47,15 @@ impl DeclarationExpression for Option<Expression> {
 		settings: &ParseSettings,
 	) -> ParseResult<Self> {
 		if let Some(Token(TSXToken::Assign, _)) = reader.peek() {
-			reader.next();
+			let Token(_, assign_position) = reader.next().unwrap();
+			if reader.peek().is_none() {
+				// e.g. a REPL line that is just `let x =` -- the user is still typing, this isn't a
+				// real syntax error yet.
+				return Err(ParseError::new(
+					crate::ParseErrors::UnexpectedEndOfInput { expecting: EXPECTING_NAME },
+					assign_position,
+				));
+			}
 			let expression = Expression::from_reader(reader, state, settings)?;
 			Ok(Some(expression))
 		} else {
@@ -73,7 +90,22 @@ impl DeclarationExpression for crate::Expression {
 		state: &mut crate::ParsingState,
 		settings: &ParseSettings,
 	) -> ParseResult<Self> {
-		reader.expect_next(TSXToken::Assign)?;
+		if !matches!(reader.peek(), Some(Token(TSXToken::Assign, _))) {
+			// No `=` at all, e.g. `const x;` -- kept distinct from a malformed expression after a
+			// present `=` so that only this case gets the "insert a value" suggestion below.
+			return Err(ParseError::new(
+				crate::ParseErrors::ExpectedInitializer,
+				reader.peek().map_or_else(Span::default, |Token(_, position)| position.clone()),
+			));
+		}
+		let assign_position = reader.expect_next(TSXToken::Assign)?;
+		if reader.peek().is_none() {
+			// e.g. a REPL line that is just `const x =` -- keep buffering rather than erroring.
+			return Err(ParseError::new(
+				crate::ParseErrors::UnexpectedEndOfInput { expecting: EXPECTING_NAME },
+				assign_position,
+			));
+		}
 		Expression::from_reader(reader, state, settings)
 	}
 
@@ -108,12 +140,19 @@ pub struct VariableDeclaration<TExpr: DeclarationExpression> {
 impl<TExpr: DeclarationExpression + 'static> ASTNode for VariableDeclaration<TExpr> {
 	fn get_position(&self) -> Cow<Span> {
 		let name_position = self.name.get_position();
-		if let Some(expr_pos) = TExpr::get_decl_position(&self.expression) {
-			Cow::Owned(name_position.union(&expr_pos))
-		} else if let Some(ref ty_ref) = self.type_reference {
-			Cow::Owned(name_position.union(&ty_ref.get_position()))
-		} else {
-			name_position
+		let end_position = TExpr::get_decl_position(&self.expression)
+			.or_else(|| self.type_reference.as_ref().map(TypeReference::get_position));
+		match end_position {
+			// The name's own span already reaches (or covers) the furthest point -- no need to
+			// allocate a union just to hand back the same range.
+			Some(end_position)
+				if name_position.start <= end_position.start
+					&& end_position.end <= name_position.end =>
+			{
+				name_position
+			}
+			Some(end_position) => Cow::Owned(name_position.union(&end_position)),
+			None => name_position,
 		}
 	}
 
@@ -126,13 +165,35 @@ impl<TExpr: DeclarationExpression + 'static> ASTNode for VariableDeclaration<TEx
 			reader, state, settings,
 		)?;
 		let type_reference = if let Some(Token(TSXToken::Colon, _)) = reader.peek() {
-			reader.next();
+			let Token(_, colon_position) = reader.next().unwrap();
+			if reader.peek().is_none() {
+				// e.g. a REPL line that is just `let x:` -- the type annotation hasn't been typed
+				// yet, so this should read as "needs more input", not a hard parse error.
+				return Err(ParseError::new(
+					crate::ParseErrors::UnexpectedEndOfInput { expecting: EXPECTING_NAME },
+					colon_position,
+				));
+			}
 			let type_reference = TypeReference::from_reader(reader, state, settings)?;
 			Some(type_reference)
 		} else {
 			None
 		};
-		let expression = TExpr::decl_from_reader(reader, state, settings)?;
+		let expression = TExpr::decl_from_reader(reader, state, settings).map_err(|err| {
+			// Only offer the "insert a value" fix-it when the initializer was missing entirely
+			// (`ExpectedInitializer`, e.g. `const x;`) -- not for a malformed expression after a
+			// present `=` (e.g. `const x = )`), where applying it would produce `x= /* value */ = )`.
+			if matches!(err.reason, crate::ParseErrors::ExpectedInitializer) {
+				let name_position = name.get_position().into_owned();
+				err.with_suggestion(Suggestion::new(
+					Span { start: name_position.end, ..name_position },
+					"= /* value */",
+					"a value must be provided here",
+				))
+			} else {
+				err
+			}
+		})?;
 		Ok(Self { name, type_reference, expression })
 	}
 
@@ -151,21 +212,35 @@ impl<TExpr: DeclarationExpression + 'static> ASTNode for VariableDeclaration<TEx
 	}
 }
 
-/// TODO smallvec the declarations
+/// The overwhelming majority of `let`/`const`/`var`/`using` statements declare exactly one binding,
+/// so that case lives inline instead of forcing a heap allocation per statement.
+type Declarations<TExpr> = SmallVec<[VariableDeclaration<TExpr>; 1]>;
+
 #[derive(Debug, Clone, PartialEq, Eq, Visitable)]
 #[cfg_attr(feature = "self-rust-tokenize", derive(self_rust_tokenize::SelfRustTokenize))]
 pub enum VariableStatement {
 	ConstDeclaration {
 		keyword: Keyword<tsx_keywords::Const>,
-		declarations: Vec<VariableDeclaration<Expression>>,
+		declarations: Declarations<Expression>,
 	},
 	LetDeclaration {
 		keyword: Keyword<tsx_keywords::Let>,
-		declarations: Vec<VariableDeclaration<Option<Expression>>>,
+		declarations: Declarations<Option<Expression>>,
 	},
 	VarDeclaration {
 		keyword: Keyword<tsx_keywords::Var>,
-		declarations: Vec<VariableDeclaration<Option<Expression>>>,
+		declarations: Declarations<Option<Expression>>,
+	},
+	/// TC39 explicit resource management: `using x = getResource()`. Binds like `const` (mandatory
+	/// initializer, block-scoped) but only ever as a plain identifier, never a destructuring pattern.
+	UsingDeclaration {
+		keyword: Keyword<tsx_keywords::Using>,
+		declarations: Declarations<Expression>,
+	},
+	/// `await using y = ...`. Only valid where `await` itself is valid; disposal runs asynchronously.
+	AwaitUsingDeclaration {
+		keyword: Keyword<tsx_keywords::AwaitUsing>,
+		declarations: Declarations<Expression>,
 	},
 }
 
@@ -175,24 +250,130 @@ pub enum VariableKeyword {
 	Const(Keyword<tsx_keywords::Const>),
 	Let(Keyword<tsx_keywords::Let>),
 	Var(Keyword<tsx_keywords::Var>),
+	Using(Keyword<tsx_keywords::Using>),
+	AwaitUsing(Keyword<tsx_keywords::AwaitUsing>),
+}
+
+/// Returns the variable keyword `name` is most likely a typo of, if it is a single edit away from
+/// one (e.g. `cosnt` -> `const`). Used to attach a fix-it [`Suggestion`] rather than bottoming out
+/// in a bare "unexpected token".
+fn near_miss_keyword(name: &str) -> Option<&'static str> {
+	["const", "let", "var"].into_iter().find(|keyword| is_one_edit_away(name, keyword))
+}
+
+/// Whether `a` can be turned into `b` via a single character insertion, deletion, substitution, or
+/// transposition (a classic "fat-finger" distance of one).
+fn is_one_edit_away(a: &str, b: &str) -> bool {
+	if a == b {
+		return false;
+	}
+	let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+	if a.len().abs_diff(b.len()) > 1 {
+		return false;
+	}
+	// Levenshtein distance plus adjacent transpositions (optimal string alignment), with an
+	// early-out, which is all we need for a distance-of-one check.
+	let (mut prev2, mut prev, mut curr): (Vec<usize>, Vec<usize>, Vec<usize>) =
+		(vec![0; b.len() + 1], (0..=b.len()).collect(), vec![0; b.len() + 1]);
+	for (i, ca) in a.iter().enumerate() {
+		curr[0] = i + 1;
+		for (j, cb) in b.iter().enumerate() {
+			curr[j + 1] = if ca == cb {
+				prev[j]
+			} else {
+				1 + prev[j].min(prev[j + 1]).min(curr[j])
+			};
+			if i > 0 && j > 0 && *ca == b[j - 1] && a[i - 1] == *cb {
+				curr[j + 1] = curr[j + 1].min(prev2[j - 1] + 1);
+			}
+		}
+		std::mem::swap(&mut prev2, &mut prev);
+		std::mem::swap(&mut prev, &mut curr);
+	}
+	prev[b.len()] == 1
 }
 
 impl VariableKeyword {
-	pub fn is_token_variable_keyword(token: &TSXToken) -> bool {
-		matches!(token, TSXToken::Keyword(TSXKeyword::Const | TSXKeyword::Let | TSXKeyword::Var))
+	/// Takes the reader (rather than a single already-peeked token) so that `await using` can be
+	/// recognised with its statement dispatched here: `await` alone is ambiguous with an `await`
+	/// expression, so a second token of lookahead is needed to tell `await using x = f()` apart
+	/// from `await f()`.
+	pub fn is_token_variable_keyword(reader: &mut impl TokenReader<TSXToken, Span>) -> bool {
+		match reader.peek() {
+			Some(Token(
+				TSXToken::Keyword(
+					TSXKeyword::Const | TSXKeyword::Let | TSXKeyword::Var | TSXKeyword::Using,
+				),
+				_,
+			)) => true,
+			Some(Token(TSXToken::Keyword(TSXKeyword::Await), _)) => {
+				matches!(
+					reader.peek_n(1),
+					Some(Token(TSXToken::Keyword(TSXKeyword::Using), _))
+				)
+			}
+			_ => false,
+		}
 	}
 
-	pub(crate) fn from_reader(token: Token<TSXToken, Span>) -> ParseResult<Self> {
+	/// Takes the reader (rather than a single pre-read token) so that `await using` -- two tokens
+	/// -- can be recognised as one keyword, the same way a bare `using` is.
+	pub(crate) fn from_reader(
+		reader: &mut impl TokenReader<TSXToken, Span>,
+		state: &crate::ParsingState,
+	) -> ParseResult<Self> {
+		let token = reader.next().ok_or_else(parse_lexing_error)?;
 		match token {
 			Token(TSXToken::Keyword(TSXKeyword::Const), pos) => Ok(Self::Const(Keyword::new(pos))),
 			Token(TSXToken::Keyword(TSXKeyword::Let), pos) => Ok(Self::Let(Keyword::new(pos))),
 			Token(TSXToken::Keyword(TSXKeyword::Var), pos) => Ok(Self::Var(Keyword::new(pos))),
+			Token(TSXToken::Keyword(TSXKeyword::Using), pos) => Ok(Self::Using(Keyword::new(pos))),
+			Token(TSXToken::Keyword(TSXKeyword::Await), await_position) if state.allows_await() => {
+				match reader.peek() {
+					Some(Token(TSXToken::Keyword(TSXKeyword::Using), _)) => {
+						let Token(_, using_position) = reader.next().unwrap();
+						Ok(Self::AwaitUsing(Keyword::new(await_position.union(&using_position))))
+					}
+					Some(_) => {
+						let Token(found, position) = reader.next().unwrap();
+						Err(ParseError::new(
+							crate::ParseErrors::UnexpectedToken {
+								expected: &[TSXToken::Keyword(TSXKeyword::Using)],
+								found,
+							},
+							position,
+						))
+					}
+					None => Err(parse_lexing_error()),
+				}
+			}
+			Token(TSXToken::IdentifierLiteral(name), position) if near_miss_keyword(&name).is_some() => {
+				let suggestion = near_miss_keyword(&name).unwrap();
+				Err(ParseError::new(
+					crate::ParseErrors::UnexpectedToken {
+						expected: &[
+							TSXToken::Keyword(TSXKeyword::Const),
+							TSXToken::Keyword(TSXKeyword::Let),
+							TSXToken::Keyword(TSXKeyword::Var),
+							TSXToken::Keyword(TSXKeyword::Using),
+						],
+						found: TSXToken::IdentifierLiteral(name),
+					},
+					position.clone(),
+				)
+				.with_suggestion(Suggestion::new(
+					position,
+					suggestion,
+					format!("did you mean `{suggestion}`?"),
+				)))
+			}
 			Token(token, position) => Err(ParseError::new(
 				crate::ParseErrors::UnexpectedToken {
 					expected: &[
 						TSXToken::Keyword(TSXKeyword::Const),
 						TSXToken::Keyword(TSXKeyword::Let),
 						TSXToken::Keyword(TSXKeyword::Var),
+						TSXToken::Keyword(TSXKeyword::Using),
 					],
 					found: token,
 				},
@@ -206,6 +387,8 @@ impl VariableKeyword {
 			VariableKeyword::Const(_) => "const ",
 			VariableKeyword::Let(_) => "let ",
 			VariableKeyword::Var(_) => "var ",
+			VariableKeyword::Using(_) => "using ",
+			VariableKeyword::AwaitUsing(_) => "await using ",
 		}
 	}
 
@@ -214,8 +397,65 @@ impl VariableKeyword {
 			VariableKeyword::Const(kw) => kw.get_position(),
 			VariableKeyword::Let(kw) => kw.get_position(),
 			VariableKeyword::Var(kw) => kw.get_position(),
+			VariableKeyword::Using(kw) => kw.get_position(),
+			VariableKeyword::AwaitUsing(kw) => kw.get_position(),
+		}
+	}
+}
+
+/// Parses the comma-separated declarations shared by `const`, `using` and `await using` -- all
+/// three require an initializer on every binding. `plain_binding_only` rejects a destructuring
+/// pattern (required for `using`/`await using`, which the spec only allows on a plain identifier)
+/// and suppresses the "did you mean `let`" suggestion (which only makes sense for `const`).
+fn parse_expression_initialized_declarations(
+	reader: &mut impl TokenReader<TSXToken, Span>,
+	state: &mut crate::ParsingState,
+	settings: &ParseSettings,
+	keyword_position: &Span,
+	plain_binding_only: bool,
+) -> ParseResult<Declarations<Expression>> {
+	let mut declarations = SmallVec::new();
+	loop {
+		let value = VariableDeclaration::<Expression>::from_reader(reader, state, settings)
+			.map_err(|err| {
+				if plain_binding_only
+					|| !matches!(err.reason, crate::ParseErrors::ExpectedInitializer)
+				{
+					err
+				} else {
+					// `const` without an initializer (e.g. `const x;`) can't be fixed by adding a
+					// value alone if the author actually meant `let` -- offer that as an
+					// alternative fix-it alongside the one `VariableDeclaration` attaches for the
+					// missing value.
+					err.with_suggestion(Suggestion::new(
+						keyword_position.clone(),
+						"let ",
+						"`const` declarations must be initialized; use `let`",
+					))
+				}
+			})?;
+		if plain_binding_only && !matches!(&*value.name, VariableField::Name(_)) {
+			return Err(ParseError::new(
+				crate::ParseErrors::InvalidUsingBindingPattern,
+				value.name.get_position().into_owned(),
+			));
+		}
+		declarations.push(value);
+		if matches!(reader.peek(), Some(Token(TSXToken::Comma, _))) {
+			let Token(_, comma_position) = reader.next().unwrap();
+			if reader.peek().is_none() {
+				// e.g. a REPL line that is just `using x = r(),` -- another binding is expected
+				// but hasn't been typed yet.
+				return Err(ParseError::new(
+					crate::ParseErrors::UnexpectedEndOfInput { expecting: EXPECTING_NAME },
+					comma_position,
+				));
+			}
+		} else {
+			break;
 		}
 	}
+	Ok(declarations)
 }
 
 impl ASTNode for VariableStatement {
@@ -224,17 +464,25 @@ impl ASTNode for VariableStatement {
 		state: &mut crate::ParsingState,
 		settings: &ParseSettings,
 	) -> ParseResult<Self> {
-		let kind = VariableKeyword::from_reader(reader.next().ok_or_else(parse_lexing_error)?)?;
+		let kind = VariableKeyword::from_reader(reader, state)?;
 		Ok(match kind {
 			VariableKeyword::Let(..) | VariableKeyword::Var(..) => {
-				let mut declarations = Vec::new();
+				let mut declarations = SmallVec::new();
 				loop {
 					let value = VariableDeclaration::<Option<Expression>>::from_reader(
 						reader, state, settings,
 					)?;
 					declarations.push(value);
 					if matches!(reader.peek(), Some(Token(TSXToken::Comma, _))) {
-						reader.next();
+						let Token(_, comma_position) = reader.next().unwrap();
+						if reader.peek().is_none() {
+							// e.g. a REPL line that is just `let x,` -- another binding is expected
+							// but hasn't been typed yet.
+							return Err(ParseError::new(
+								crate::ParseErrors::UnexpectedEndOfInput { expecting: EXPECTING_NAME },
+								comma_position,
+							));
+						}
 					} else {
 						break;
 					}
@@ -250,19 +498,35 @@ impl ASTNode for VariableStatement {
 				}
 			}
 			VariableKeyword::Const(keyword) => {
-				let mut declarations = Vec::new();
-				loop {
-					let value =
-						VariableDeclaration::<Expression>::from_reader(reader, state, settings)?;
-					declarations.push(value);
-					if matches!(reader.peek().unwrap().0, TSXToken::Comma) {
-						reader.next();
-					} else {
-						break;
-					}
-				}
+				let declarations = parse_expression_initialized_declarations(
+					reader,
+					state,
+					settings,
+					keyword.get_position(),
+					false,
+				)?;
 				VariableStatement::ConstDeclaration { keyword, declarations }
 			}
+			VariableKeyword::Using(keyword) => {
+				let declarations = parse_expression_initialized_declarations(
+					reader,
+					state,
+					settings,
+					keyword.get_position(),
+					true,
+				)?;
+				VariableStatement::UsingDeclaration { keyword, declarations }
+			}
+			VariableKeyword::AwaitUsing(keyword) => {
+				let declarations = parse_expression_initialized_declarations(
+					reader,
+					state,
+					settings,
+					keyword.get_position(),
+					true,
+				)?;
+				VariableStatement::AwaitUsingDeclaration { keyword, declarations }
+			}
 		})
 	}
 
@@ -298,26 +562,271 @@ impl ASTNode for VariableStatement {
 				buf.push_str("const ");
 				declarations_to_string(declarations, buf, settings, depth);
 			}
+			VariableStatement::UsingDeclaration { declarations, .. } => {
+				buf.push_str("using ");
+				declarations_to_string(declarations, buf, settings, depth);
+			}
+			VariableStatement::AwaitUsingDeclaration { declarations, .. } => {
+				buf.push_str("await using ");
+				declarations_to_string(declarations, buf, settings, depth);
+			}
 		}
 	}
 
 	fn get_position(&self) -> Cow<Span> {
+		// Pulled out since all five variants do the same keyword-to-last-declaration union. The
+		// union is skipped only when the last declaration's span equals the keyword's span; as a
+		// declaration follows its keyword, a parsed statement always gets the owned union.
+		fn keyword_to_declarations_position<TExpr: DeclarationExpression + 'static>(
+			keyword_position: &Span,
+			declarations: &[VariableDeclaration<TExpr>],
+		) -> Cow<Span> {
+			let last_position = declarations.last().unwrap().get_position();
+			if *last_position == *keyword_position {
+				last_position
+			} else {
+				Cow::Owned(keyword_position.union(&last_position))
+			}
+		}
+
 		match self {
 			VariableStatement::ConstDeclaration { keyword, declarations } => {
-				Cow::Owned(keyword.1.union(&declarations.last().unwrap().get_position()))
+				keyword_to_declarations_position(&keyword.1, declarations)
 			}
 			VariableStatement::LetDeclaration { keyword, declarations } => {
-				Cow::Owned(keyword.1.union(&declarations.last().unwrap().get_position()))
+				keyword_to_declarations_position(&keyword.1, declarations)
 			}
 			VariableStatement::VarDeclaration { keyword, declarations } => {
-				Cow::Owned(keyword.1.union(&declarations.last().unwrap().get_position()))
+				keyword_to_declarations_position(&keyword.1, declarations)
+			}
+			VariableStatement::UsingDeclaration { keyword, declarations } => {
+				keyword_to_declarations_position(&keyword.1, declarations)
+			}
+			VariableStatement::AwaitUsingDeclaration { keyword, declarations } => {
+				keyword_to_declarations_position(&keyword.1, declarations)
 			}
 		}
 	}
 }
 
+impl<TExpr: DeclarationExpression + 'static> VariableDeclaration<TExpr> {
+	/// Every identifier this declaration binds, including ones nested inside array/object
+	/// destructuring, each paired with the span of that specific binding (not the whole pattern).
+	pub fn get_declared_names(&self) -> Vec<(Cow<str>, Span)> {
+		let mut names = Vec::new();
+		collect_declared_names(&self.name, &mut names);
+		names
+	}
+}
+
 impl VariableStatement {
 	pub fn is_constant(&self) -> bool {
 		matches!(self, VariableStatement::ConstDeclaration { .. })
 	}
+
+	/// Every identifier bound across all of this statement's declarations, e.g. both `a` and `b`
+	/// in `let a = 1, b = 2`, and every leaf binding in a destructuring pattern.
+	pub fn get_declared_names(&self) -> Vec<(Cow<str>, Span)> {
+		match self {
+			VariableStatement::ConstDeclaration { declarations, .. }
+			| VariableStatement::UsingDeclaration { declarations, .. }
+			| VariableStatement::AwaitUsingDeclaration { declarations, .. } => {
+				declarations.iter().flat_map(VariableDeclaration::get_declared_names).collect()
+			}
+			VariableStatement::LetDeclaration { declarations, .. }
+			| VariableStatement::VarDeclaration { declarations, .. } => {
+				declarations.iter().flat_map(VariableDeclaration::get_declared_names).collect()
+			}
+		}
+	}
+}
+
+fn collect_declared_names(
+	field: &WithComment<VariableField<VariableFieldInSourceCode>>,
+	names: &mut Vec<(Cow<str>, Span)>,
+) {
+	collect_declared_names_from_field(field, names)
+}
+
+fn collect_declared_names_from_field(
+	field: &VariableField<VariableFieldInSourceCode>,
+	names: &mut Vec<(Cow<str>, Span)>,
+) {
+	match field {
+		VariableField::Name(identifier) => {
+			names.push((
+				Cow::Owned(identifier.as_str().to_owned()),
+				identifier.get_position().into_owned(),
+			));
+		}
+		VariableField::Array(elements, _) => {
+			for element in elements {
+				match element {
+					ArrayDestructuringField::Name(inner, _, _) => collect_declared_names(inner, names),
+					ArrayDestructuringField::Spread(inner, _) => {
+						collect_declared_names_from_field(inner, names)
+					}
+					ArrayDestructuringField::None => {}
+				}
+			}
+		}
+		VariableField::Object(properties, _) => {
+			for property in properties {
+				match property {
+					ObjectDestructuringField::Name(identifier, _, _, position) => {
+						names.push((Cow::Owned(identifier.as_str().to_owned()), position.clone()));
+					}
+					ObjectDestructuringField::Map { name, .. } => collect_declared_names(name, names),
+					ObjectDestructuringField::Spread(identifier, position) => {
+						names.push((Cow::Owned(identifier.as_str().to_owned()), position.clone()));
+					}
+				}
+			}
+		}
+	}
+}
+
+/// Flags `let`/`const` bindings that redeclare a name already bound earlier in the same block,
+/// mirroring how a name living in two namespaces at once forces a clash. `var` is intentionally
+/// excluded -- redeclaring a `var` is legal JavaScript.
+///
+/// This is unintegrated groundwork: nothing in this module calls it automatically during parsing.
+/// Wiring it in is the responsibility of whatever walks a block's statement list (each block's own
+/// module), which isn't part of `statements::variable` -- call this over that list's
+/// `VariableStatement`s once that caller exists.
+pub fn find_redeclared_variables<'a>(
+	statements: impl IntoIterator<Item = &'a VariableStatement>,
+) -> Vec<ParseError> {
+	let mut seen: std::collections::HashMap<String, Span> = std::collections::HashMap::new();
+	let mut errors = Vec::new();
+	for statement in statements {
+		if matches!(statement, VariableStatement::VarDeclaration { .. }) {
+			continue;
+		}
+		for (name, position) in statement.get_declared_names() {
+			match seen.entry(name.into_owned()) {
+				std::collections::hash_map::Entry::Occupied(entry) => {
+					errors.push(ParseError::new(
+						crate::ParseErrors::VariableRedeclaration {
+							name: entry.key().clone(),
+							original: entry.get().clone(),
+						},
+						position,
+					));
+				}
+				std::collections::hash_map::Entry::Vacant(entry) => {
+					entry.insert(position);
+				}
+			}
+		}
+	}
+	errors
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse_variable_statement(source: &str) -> VariableStatement {
+		VariableStatement::from_string(source.to_owned(), ParseSettings::default())
+			.expect("statement should parse")
+	}
+
+	#[test]
+	fn using_declaration_parses() {
+		let statement = parse_variable_statement("using resource = getResource();");
+		assert!(matches!(statement, VariableStatement::UsingDeclaration { .. }));
+	}
+
+	#[test]
+	fn await_using_declaration_parses() {
+		// `await` and `using` are two tokens; `VariableKeyword::from_reader` has to read both as one
+		// `await using` keyword rather than stopping at the `await`.
+		let statement = parse_variable_statement("await using resource = getResource();");
+		assert!(matches!(statement, VariableStatement::AwaitUsingDeclaration { .. }));
+	}
+
+	#[test]
+	fn using_rejects_destructuring_binding() {
+		let result =
+			VariableStatement::from_string("using { a } = r;".to_owned(), ParseSettings::default());
+		assert!(matches!(
+			result.unwrap_err().reason,
+			crate::ParseErrors::InvalidUsingBindingPattern
+		));
+	}
+
+	#[test]
+	fn missing_initializer_gets_insert_a_value_suggestion() {
+		let err = VariableStatement::from_string("const x;".to_owned(), ParseSettings::default())
+			.unwrap_err();
+		assert!(matches!(err.reason, crate::ParseErrors::ExpectedInitializer));
+		assert_eq!(err.suggestions.len(), 2);
+		assert_eq!(err.suggestions[0].replacement, "= /* value */");
+		assert_eq!(err.suggestions[1].replacement, "let ");
+	}
+
+	#[test]
+	fn malformed_expression_after_assign_does_not_get_insert_a_value_suggestion() {
+		// A present `=` followed by a bad expression is a different failure to a missing `=`
+		// entirely -- applying the "insert a value" fix-it here would produce a double `=`.
+		let err = VariableStatement::from_string("const x = );".to_owned(), ParseSettings::default())
+			.unwrap_err();
+		assert!(!matches!(err.reason, crate::ParseErrors::ExpectedInitializer));
+		assert!(err.suggestions.is_empty());
+	}
+
+	#[test]
+	fn eof_after_assign_is_incomplete_with_expecting_populated() {
+		// e.g. a REPL line that is just `let x =` -- the user is still typing, so this should
+		// report "needs more input" with something to tell the user what comes next.
+		let err =
+			VariableStatement::from_string("let x =".to_owned(), ParseSettings::default()).unwrap_err();
+		assert!(err.is_incomplete());
+		assert!(matches!(
+			err.reason,
+			crate::ParseErrors::UnexpectedEndOfInput { expecting } if !expecting.is_empty()
+		));
+	}
+
+	#[test]
+	fn eof_after_colon_is_incomplete() {
+		let err =
+			VariableStatement::from_string("let x:".to_owned(), ParseSettings::default()).unwrap_err();
+		assert!(err.is_incomplete());
+	}
+
+	#[test]
+	fn eof_after_comma_is_incomplete() {
+		let err =
+			VariableStatement::from_string("let x = 1,".to_owned(), ParseSettings::default())
+				.unwrap_err();
+		assert!(err.is_incomplete());
+	}
+
+	#[test]
+	fn malformed_expression_is_not_incomplete() {
+		let err = VariableStatement::from_string("const x = );".to_owned(), ParseSettings::default())
+			.unwrap_err();
+		assert!(!err.is_incomplete());
+	}
+
+	#[test]
+	fn detects_redeclared_let_binding() {
+		let first = parse_variable_statement("let a = 1;");
+		let second = parse_variable_statement("let a = 2;");
+		let errors = find_redeclared_variables([&first, &second]);
+		assert_eq!(errors.len(), 1);
+		assert!(matches!(
+			&errors[0].reason,
+			crate::ParseErrors::VariableRedeclaration { name, .. } if name == "a"
+		));
+	}
+
+	#[test]
+	fn var_redeclaration_is_allowed() {
+		let first = parse_variable_statement("var a = 1;");
+		let second = parse_variable_statement("var a = 2;");
+		assert!(find_redeclared_variables([&first, &second]).is_empty());
+	}
 }